@@ -1,14 +1,49 @@
 use figlet_rs::FIGfont;
+use once_cell::sync::Lazy;
+use std::collections::HashMap;
+use std::sync::Mutex;
 
-/// Renders a text string into an ASCII-art header
-pub fn render_ascii_header(text: &str) -> String {
-    // Standard font bundled with figlet-rs
-    let standard_font = FIGfont::standand().unwrap();
-    let figure = standard_font.convert(text);
+/// Directory additional FIGfont (`.flf`) files are loaded from at startup,
+/// alongside the bundled standard font.
+const FONTS_DIR: &str = "fonts";
 
-    match figure {
-        Some(fig) => fig.to_string(),
-        None => text.to_string(),
+static FONTS: Lazy<Mutex<HashMap<String, FIGfont>>> = Lazy::new(|| Mutex::new(load_fonts()));
+
+fn load_fonts() -> HashMap<String, FIGfont> {
+    let mut fonts = HashMap::new();
+
+    if let Ok(standard) = FIGfont::standard() {
+        fonts.insert("standard".to_string(), standard);
+    }
+
+    if let Ok(entries) = std::fs::read_dir(FONTS_DIR) {
+        for entry in entries.flatten() {
+            let path = entry.path();
+            if path.extension().and_then(|ext| ext.to_str()) != Some("flf") {
+                continue;
+            }
+            let Some(name) = path.file_stem().and_then(|s| s.to_str()) else {
+                continue;
+            };
+            if let Some(path_str) = path.to_str() {
+                if let Ok(font) = FIGfont::from_file(path_str) {
+                    fonts.insert(name.to_string(), font);
+                }
+            }
+        }
     }
+
+    fonts
 }
 
+/// Renders `text` with the named font, falling back to the bundled standard
+/// font when `font_name` hasn't been loaded (rather than when a `figlet`
+/// binary happens to be missing). Returns `None` if even the standard font
+/// failed to load, or if the chosen font cannot render `text` (its
+/// `convert` yields no figure); that case does not fall back to "standard".
+pub fn render_with_font(text: &str, font_name: &str) -> Option<Vec<String>> {
+    let fonts = FONTS.lock().unwrap();
+    let font = fonts.get(font_name).or_else(|| fonts.get("standard"))?;
+    let figure = font.convert(text)?;
+    Some(figure.to_string().lines().map(String::from).collect())
+}
@@ -0,0 +1,214 @@
+use std::collections::HashMap;
+
+use chrono::{Datelike, Duration, Months, NaiveDate};
+use ratatui::{
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Paragraph, Wrap},
+};
+
+/// State for the Calendar screen: the month currently being viewed, the
+/// selected day within it, and the events attached to specific days.
+pub struct CalendarState {
+    pub viewing: NaiveDate,
+    pub selected: NaiveDate,
+    pub events: HashMap<NaiveDate, Vec<String>>,
+}
+
+impl CalendarState {
+    pub fn new(today: NaiveDate) -> Self {
+        Self {
+            viewing: today,
+            selected: today,
+            events: HashMap::new(),
+        }
+    }
+
+    /// Moves the selected day by `delta` days (use `±7` for a week),
+    /// following the viewed month along if the selection crosses into
+    /// the next/previous one.
+    pub fn move_selected_days(&mut self, delta: i64) {
+        self.selected += Duration::days(delta);
+        self.viewing = self.selected;
+    }
+
+    /// Jumps the viewed (and selected) month by `delta` months, clamping the
+    /// day to the target month's last day instead of bailing out — shifting
+    /// the day-of-month directly (e.g. Jan 31 + 1 month) fails whenever the
+    /// target month is shorter, which would otherwise leave navigation
+    /// stuck on any day past the 28th.
+    pub fn move_month(&mut self, delta: i32) {
+        let day = self.viewing.day();
+        let first_of_month = self.viewing.with_day(1).expect("day 1 always valid");
+
+        let shifted_first = if delta >= 0 {
+            first_of_month.checked_add_months(Months::new(delta as u32))
+        } else {
+            first_of_month.checked_sub_months(Months::new((-delta) as u32))
+        };
+        let Some(shifted_first) = shifted_first else {
+            return;
+        };
+
+        let clamped_day = day.min(days_in_month(shifted_first));
+        let date = shifted_first
+            .with_day(clamped_day)
+            .expect("clamped day is always valid for its own month");
+
+        self.viewing = date;
+        self.selected = date;
+    }
+
+    /// Returns the selection and view to today.
+    pub fn goto_today(&mut self, today: NaiveDate) {
+        self.viewing = today;
+        self.selected = today;
+    }
+
+    pub fn events_on(&self, date: NaiveDate) -> &[String] {
+        self.events.get(&date).map(Vec::as_slice).unwrap_or(&[])
+    }
+}
+
+/// Renders the month-grid for `state.viewing`, highlighting today and the
+/// selected day, with a side panel listing the selected day's events.
+pub fn draw(f: &mut ratatui::Frame, state: &CalendarState, today: NaiveDate) {
+    let size = f.size();
+
+    let columns = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Percentage(70), Constraint::Percentage(30)].as_ref())
+        .split(size);
+
+    draw_month_grid(f, columns[0], state, today);
+    draw_side_panel(f, columns[1], state);
+}
+
+fn draw_month_grid(f: &mut ratatui::Frame, area: Rect, state: &CalendarState, today: NaiveDate) {
+    let block = Block::default()
+        .title(state.viewing.format("%B %Y").to_string())
+        .borders(Borders::ALL);
+    let inner = block.inner(area);
+    f.render_widget(block, area);
+
+    let rows = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Length(1), Constraint::Min(0)].as_ref())
+        .split(inner);
+
+    let weekday_header = Line::from(
+        ["Mo", "Tu", "We", "Th", "Fr", "Sa", "Su"]
+            .iter()
+            .map(|d| Span::raw(format!("{d:>4}")))
+            .collect::<Vec<_>>(),
+    );
+    f.render_widget(Paragraph::new(weekday_header), rows[0]);
+
+    let weeks = month_weeks(state.viewing);
+    let week_rows = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints(vec![Constraint::Length(1); weeks.len()])
+        .split(rows[1]);
+
+    for (week, row) in weeks.iter().zip(week_rows.iter()) {
+        let day_cols = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints(vec![Constraint::Length(4); 7])
+            .split(*row);
+
+        for (day, col) in week.iter().zip(day_cols.iter()) {
+            let Some(date) = day else { continue };
+
+            let mut style = Style::default();
+            if *date == today {
+                style = style.fg(Color::Yellow);
+            }
+            if *date == state.selected {
+                style = style.add_modifier(Modifier::REVERSED | Modifier::BOLD);
+            }
+            if !state.events_on(*date).is_empty() {
+                style = style.add_modifier(Modifier::UNDERLINED);
+            }
+
+            let label = Paragraph::new(format!("{:>4}", date.day()))
+                .style(style)
+                .alignment(ratatui::layout::Alignment::Right);
+            f.render_widget(label, *col);
+        }
+    }
+}
+
+fn draw_side_panel(f: &mut ratatui::Frame, area: Rect, state: &CalendarState) {
+    let block = Block::default()
+        .title(state.selected.format("%a %d %b %Y").to_string())
+        .borders(Borders::ALL);
+
+    let events = state.events_on(state.selected);
+    let lines: Vec<Line> = if events.is_empty() {
+        vec![Line::from(Span::raw("No events"))]
+    } else {
+        events.iter().map(|e| Line::from(Span::raw(format!("- {e}")))).collect()
+    };
+
+    let paragraph = Paragraph::new(lines).block(block).wrap(Wrap { trim: true });
+    f.render_widget(paragraph, area);
+}
+
+/// Lays `viewing`'s month out as Monday-first weeks, padding the first and
+/// last week with `None` for days outside the month.
+fn month_weeks(viewing: NaiveDate) -> Vec<[Option<NaiveDate>; 7]> {
+    let first_of_month = viewing.with_day(1).expect("day 1 always valid");
+    let lead_blanks = first_of_month.weekday().num_days_from_monday() as i64;
+    let grid_start = first_of_month - Duration::days(lead_blanks);
+
+    let total_days = lead_blanks + i64::from(days_in_month(first_of_month));
+    let weeks_needed = (total_days + 6) / 7;
+
+    (0..weeks_needed)
+        .map(|week| {
+            let mut days: [Option<NaiveDate>; 7] = [None; 7];
+            for (offset, slot) in days.iter_mut().enumerate() {
+                let date = grid_start + Duration::days(week * 7 + offset as i64);
+                if date.month() == viewing.month() && date.year() == viewing.year() {
+                    *slot = Some(date);
+                }
+            }
+            days
+        })
+        .collect()
+}
+
+/// Returns the number of days in the month `first_of_month` (which must be
+/// the first day of that month) falls in.
+fn days_in_month(first_of_month: NaiveDate) -> u32 {
+    let next_month = first_of_month
+        .checked_add_months(Months::new(1))
+        .expect("month arithmetic in range");
+    (next_month - first_of_month).num_days() as u32
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(y: i32, m: u32, d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, d).expect("valid test date")
+    }
+
+    #[test]
+    fn move_month_clamps_day_crossing_into_a_shorter_month() {
+        let mut state = CalendarState::new(date(2026, 1, 31));
+        state.move_month(1);
+        assert_eq!(state.viewing, date(2026, 2, 28));
+        assert_eq!(state.selected, date(2026, 2, 28));
+    }
+
+    #[test]
+    fn move_month_wraps_the_year_backwards() {
+        let mut state = CalendarState::new(date(2026, 1, 15));
+        state.move_month(-1);
+        assert_eq!(state.viewing, date(2025, 12, 15));
+        assert_eq!(state.selected, date(2025, 12, 15));
+    }
+}
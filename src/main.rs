@@ -1,22 +1,21 @@
+mod ascii_header;
+mod calendar;
+mod tui;
 mod ui_pages;
 use once_cell::sync::Lazy;
 use std::collections::HashMap;
 use std::sync::Mutex;
-use crossterm::{
-    event::{self, DisableMouseCapture, EnableMouseCapture, Event, KeyCode, KeyEvent},
-    execute,
-    terminal::{disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen},
-};
 use ratatui::{
-    backend::CrosstermBackend,
     layout::{Alignment, Constraint, Direction, Layout, Rect},
     style::{Modifier, Style, Color},
     text::{Line, Span},
     widgets::{Block, Borders, List, ListItem, Paragraph, Wrap},
-    Terminal,
 };
 
-use std::{error::Error, io, time::Duration};
+use std::{error::Error, time::Duration};
+
+use tui::Key;
+use ui_pages::{Focus, TodoListState};
 
 #[derive(Debug, Clone, Copy, PartialEq)]
 enum Screen {
@@ -32,6 +31,9 @@ struct App {
     menu_items: Vec<&'static str>,
     selected: usize,
     screen: Screen,
+    input_focus: Focus,
+    todo: TodoListState,
+    calendar: calendar::CalendarState,
 }
 
 impl App {
@@ -46,6 +48,9 @@ impl App {
             menu_items,
             selected: 0,
             screen: Screen::Main,
+            input_focus: Focus::List,
+            todo: TodoListState::new(),
+            calendar: calendar::CalendarState::new(chrono::Local::now().date_naive()),
         }
     }
 
@@ -70,31 +75,24 @@ impl App {
             4 => Screen::Configuration,
             _ => Screen::Main,
         };
+        self.input_focus = Focus::List;
     }
 
     fn back_to_main(&mut self) {
         self.screen = Screen::Main;
+        self.input_focus = Focus::List;
     }
 }
 
 fn main() -> Result<(), Box<dyn Error>> {
-    // Setup terminal
-    enable_raw_mode()?;
-    let mut stdout = io::stdout();
-    execute!(stdout, EnterAlternateScreen, EnableMouseCapture)?;
-    let backend = CrosstermBackend::new(stdout);
-    let mut terminal = Terminal::new(backend)?;
-
-    // ensure we restore terminal on panic/exit
+    let mut terminal = match inline_rows_from_args() {
+        Some(rows) => tui::init_with_options(tui::Viewport::Inline(rows)),
+        None => tui::init(),
+    };
+
     let res = run_app(&mut terminal);
 
-    // restore
-    disable_raw_mode()?;
-    execute!(
-        terminal.backend_mut(),
-        LeaveAlternateScreen,
-        DisableMouseCapture
-    )?;
+    tui::restore();
     terminal.show_cursor()?;
 
     if let Err(err) = res {
@@ -105,29 +103,79 @@ fn main() -> Result<(), Box<dyn Error>> {
     Ok(())
 }
 
-fn run_app(terminal: &mut Terminal<CrosstermBackend<io::Stdout>>) -> Result<(), Box<dyn Error>> {
+/// Parses `--inline <rows>` from the process args, so Ducky can run inline
+/// beneath the shell prompt instead of always taking over the alternate
+/// screen. Returns `None` if the flag wasn't passed or wasn't a valid row
+/// count.
+fn inline_rows_from_args() -> Option<u16> {
+    let args: Vec<String> = std::env::args().collect();
+    let flag_index = args.iter().position(|arg| arg == "--inline")?;
+    args.get(flag_index + 1)?.parse().ok()
+}
+
+fn run_app<B: ratatui::backend::Backend>(
+    terminal: &mut ratatui::Terminal<B>,
+) -> Result<(), Box<dyn Error>> {
     let mut app = App::new();
 
     loop {
         terminal.draw(|f| ui(f, &app))?;
 
         // Poll for input with timeout so UI can remain responsive
-        if event::poll(Duration::from_millis(200))? {
-        match event::read()? {
-    Event::Key(KeyEvent { code, .. }) => match (app.screen, code) {
-        (Screen::Main, KeyCode::Char('q') | KeyCode::Esc) => return Ok(()),
-        (Screen::Main, KeyCode::Down | KeyCode::Char('j')) => app.next(),
-        (Screen::Main, KeyCode::Up | KeyCode::Char('k')) => app.previous(),
-        (Screen::Main, KeyCode::Enter) => app.open_selected(),
-        (screen, KeyCode::Esc) | (screen, KeyCode::Char('q')) if screen != Screen::Main => {
-            app.back_to_main()
-        }
-        (_, KeyCode::Char('h')) => app.back_to_main(),
-        _ => {}
-    },
-    Event::Mouse(_) | Event::Resize(_, _) => { /* ignore */ }
-    Event::FocusGained | Event::FocusLost | Event::Paste(_) => { /* ignore */ }
-}
+        if let Some(key) = tui::poll_event(Duration::from_millis(200))? {
+            match (app.screen, app.input_focus, key) {
+                (Screen::Main, _, Key::Char('q') | Key::Esc) => return Ok(()),
+                (Screen::Main, _, Key::Down | Key::Char('j')) => app.next(),
+                (Screen::Main, _, Key::Up | Key::Char('k')) => app.previous(),
+                (Screen::Main, _, Key::Enter) => app.open_selected(),
+
+                (Screen::TodoList, Focus::Editor, Key::Char(c)) => app.todo.edit_buffer.push(c),
+                (Screen::TodoList, Focus::Editor, Key::Backspace) => {
+                    app.todo.edit_buffer.pop();
+                }
+                (Screen::TodoList, Focus::Editor, Key::Enter) => {
+                    app.todo.commit_edit();
+                    app.input_focus = Focus::List;
+                }
+                (Screen::TodoList, Focus::Editor, Key::Esc) => {
+                    app.todo.cancel_edit();
+                    app.input_focus = Focus::List;
+                }
+
+                (Screen::TodoList, Focus::List, Key::Char('j') | Key::Down) => app.todo.move_down(),
+                (Screen::TodoList, Focus::List, Key::Char('k') | Key::Up) => app.todo.move_up(),
+                (Screen::TodoList, Focus::List, Key::Char(' ')) => app.todo.toggle_selected(),
+                (Screen::TodoList, Focus::List, Key::Char('d')) => app.todo.delete_selected(),
+                (Screen::TodoList, Focus::List, Key::Char('i')) => {
+                    app.todo.begin_insert();
+                    app.input_focus = Focus::Editor;
+                }
+                (Screen::TodoList, Focus::List, Key::Enter) if app.todo.begin_edit_selected() => {
+                    app.input_focus = Focus::Editor;
+                }
+
+                (Screen::Calendar, Focus::List, Key::Left) => app.calendar.move_selected_days(-1),
+                (Screen::Calendar, Focus::List, Key::Right) => app.calendar.move_selected_days(1),
+                (Screen::Calendar, Focus::List, Key::Up) => app.calendar.move_selected_days(-7),
+                (Screen::Calendar, Focus::List, Key::Down) => app.calendar.move_selected_days(7),
+                (Screen::Calendar, Focus::List, Key::PageUp | Key::Char('p')) => {
+                    app.calendar.move_month(-1)
+                }
+                (Screen::Calendar, Focus::List, Key::PageDown | Key::Char('n')) => {
+                    app.calendar.move_month(1)
+                }
+                (Screen::Calendar, Focus::List, Key::Char('t')) => {
+                    app.calendar.goto_today(chrono::Local::now().date_naive())
+                }
+
+                (screen, Focus::List, Key::Esc) | (screen, Focus::List, Key::Char('q'))
+                    if screen != Screen::Main =>
+                {
+                    app.back_to_main()
+                }
+                (_, Focus::List, Key::Char('h')) => app.back_to_main(),
+                _ => {}
+            }
         }
     }
 }
@@ -161,8 +209,8 @@ fn centered_rect(percent_x: u16, percent_y: u16, r: Rect) -> Rect {
 fn ui(f: &mut ratatui::Frame, app: &App) {
     match app.screen {
         Screen::Main => draw_main_menu(f, app),
-        Screen::TodoList => ui_pages::draw_fullscreen_page(f, "TodoList"),
-        Screen::Calendar => ui_pages::draw_fullscreen_page(f, "Calendar"),
+        Screen::TodoList => ui_pages::draw_todo_list(f, &app.todo, app.input_focus),
+        Screen::Calendar => calendar::draw(f, &app.calendar, chrono::Local::now().date_naive()),
         Screen::Obsidian => ui_pages::draw_fullscreen_page(f, "Obsidian"),
         Screen::WorkingOutPad => ui_pages::draw_fullscreen_page(f, "WorkingOutPad"),
         Screen::Configuration => ui_pages::draw_fullscreen_page(f, "Configuration"),
@@ -185,7 +233,7 @@ fn draw_main_menu(f: &mut ratatui::Frame, app: &App) {
         )
         .split(size);
 
-    let header = Paragraph::new(ascii_art_cached("Ducky", "alligator", Color::Red))
+    let header = Paragraph::new(ascii_art_cached("Ducky", "standard", Color::Red))
         .alignment(Alignment::Center)
         .wrap(Wrap { trim: false });
 
@@ -262,23 +310,15 @@ fn ascii_art_cached(text: &str, font: &str, colour: Color) -> Vec<Line<'static>>
         return cached.clone();
     }
 
-    // Generate ASCII art
-    let output = std::process::Command::new("figlet")
-        .arg("-f")
-        .arg(font)
-        .arg(text)
-        .output();
-
-    let ascii_lines = match output {
-        Ok(out) if out.status.success() => {
-            let ascii = String::from_utf8_lossy(&out.stdout);
-            ascii
-                .lines()
-                .map(|line| Line::from(Span::styled(line.to_string(), Style::default().fg(colour))))
-                .collect::<Vec<Line<'static>>>()
-        }
-        _ => {
-            // fallback
+    // Generate ASCII art via the bundled/loaded FIGfonts, no subprocess.
+    let ascii_lines = match ascii_header::render_with_font(text, font) {
+        Some(lines) => lines
+            .into_iter()
+            .map(|line| Line::from(Span::styled(line, Style::default().fg(colour))))
+            .collect::<Vec<Line<'static>>>(),
+        None => {
+            // fallback: no font available at all, not even "standard", or
+            // the chosen font could not render `text`
             let fallback = format!(
                 "+{line}+\n| {word} |\n+{line}+",
                 line = "-".repeat(text.len() + 2),
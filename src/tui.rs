@@ -0,0 +1,285 @@
+use std::io;
+use std::panic;
+#[cfg(feature = "crossterm")]
+use std::sync::atomic::AtomicBool;
+use std::time::Duration;
+
+use ratatui::Terminal;
+
+pub use ratatui::Viewport;
+
+/// Tracks whether the current session took over the alternate screen, so
+/// `teardown` only leaves it if `setup` entered it in the
+/// first place (inline viewports draw beneath the shell prompt instead).
+#[cfg(feature = "crossterm")]
+static ALTERNATE_SCREEN: AtomicBool = AtomicBool::new(false);
+
+#[cfg(all(feature = "crossterm", feature = "termion"))]
+compile_error!("features `crossterm` and `termion` are mutually exclusive, enable only one");
+
+#[cfg(not(any(feature = "crossterm", feature = "termion")))]
+compile_error!("enable either the `crossterm` or the `termion` backend feature");
+
+/// The concrete `ratatui` backend selected at compile time.
+#[cfg(feature = "crossterm")]
+pub type Backend = ratatui::backend::CrosstermBackend<std::io::Stdout>;
+
+#[cfg(feature = "termion")]
+pub type Backend = ratatui::backend::TermionBackend<backend::TermionWriter>;
+
+/// The terminal type Ducky draws into, parameterized over whichever
+/// backend feature is enabled.
+pub type DefaultTerminal = Terminal<Backend>;
+
+/// A backend-agnostic key, translated from whichever input library is
+/// selected at compile time so the rest of the app never names
+/// `crossterm`/`termion` types directly.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Key {
+    Char(char),
+    Enter,
+    Esc,
+    Backspace,
+    Up,
+    Down,
+    Left,
+    Right,
+    PageUp,
+    PageDown,
+    Other,
+}
+
+/// Sets up the terminal for the full-screen TUI and installs a panic hook
+/// that restores it before the default panic message is printed.
+pub fn init() -> DefaultTerminal {
+    try_init().expect("failed to initialize terminal")
+}
+
+/// Fallible version of [`init`].
+pub fn try_init() -> io::Result<DefaultTerminal> {
+    try_init_with_options(Viewport::Fullscreen)
+}
+
+/// Like [`init`], but lets the caller pick a [`Viewport`] — e.g.
+/// `Viewport::Inline(rows)` to draw Ducky in a fixed-height region beneath
+/// the shell prompt instead of taking over the alternate screen.
+pub fn init_with_options(viewport: Viewport) -> DefaultTerminal {
+    try_init_with_options(viewport).expect("failed to initialize terminal")
+}
+
+/// Fallible version of [`init_with_options`].
+pub fn try_init_with_options(viewport: Viewport) -> io::Result<DefaultTerminal> {
+    install_panic_hook();
+    backend::setup(viewport)
+}
+
+/// Restores the terminal to its original state. Call this before exiting
+/// normally; the panic hook installed by [`init`]/[`try_init`] covers the
+/// panic case.
+pub fn restore() {
+    try_restore().expect("failed to restore terminal");
+}
+
+/// Fallible version of [`restore`].
+pub fn try_restore() -> io::Result<()> {
+    backend::teardown()
+}
+
+/// Waits up to `timeout` for the next input event, returning `Ok(None)` on
+/// timeout so the draw loop can keep the UI responsive.
+pub fn poll_event(timeout: Duration) -> io::Result<Option<Key>> {
+    backend::poll_event(timeout)
+}
+
+/// Chains onto the current panic hook so that, no matter where a panic
+/// originates in the draw loop, the terminal is left in a clean state
+/// before the original panic message is printed. Without this, a panic
+/// while raw mode/the alternate screen are active leaves the user's shell
+/// garbled until they run `reset`.
+fn install_panic_hook() {
+    let original_hook = panic::take_hook();
+    panic::set_hook(Box::new(move |panic_info| {
+        let _ = try_restore();
+        original_hook(panic_info);
+    }));
+}
+
+#[cfg(feature = "crossterm")]
+mod backend {
+    use super::{Backend, DefaultTerminal, Key, Viewport, ALTERNATE_SCREEN};
+    use std::io::{self, Stdout};
+    use std::sync::atomic::Ordering;
+    use std::time::Duration;
+
+    use crossterm::{
+        event::{self, DisableMouseCapture, EnableMouseCapture, Event, KeyCode, KeyEvent},
+        execute,
+        terminal::{disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen},
+    };
+    use ratatui::{Terminal, TerminalOptions};
+
+    pub fn setup(viewport: Viewport) -> io::Result<DefaultTerminal> {
+        enable_raw_mode()?;
+        let mut stdout: Stdout = io::stdout();
+
+        // Inline viewports draw beneath the existing prompt; only a
+        // full-screen viewport takes over the alternate screen.
+        let fullscreen = matches!(viewport, Viewport::Fullscreen);
+        if fullscreen {
+            execute!(stdout, EnterAlternateScreen, EnableMouseCapture)?;
+        }
+        ALTERNATE_SCREEN.store(fullscreen, Ordering::SeqCst);
+
+        let backend: Backend = ratatui::backend::CrosstermBackend::new(stdout);
+        Terminal::with_options(backend, TerminalOptions { viewport })
+    }
+
+    pub fn teardown() -> io::Result<()> {
+        disable_raw_mode()?;
+        if ALTERNATE_SCREEN.load(Ordering::SeqCst) {
+            execute!(io::stdout(), LeaveAlternateScreen, DisableMouseCapture)?;
+        }
+        Ok(())
+    }
+
+    pub fn poll_event(timeout: Duration) -> io::Result<Option<Key>> {
+        if !event::poll(timeout)? {
+            return Ok(None);
+        }
+
+        let key = match event::read()? {
+            Event::Key(KeyEvent { code, .. }) => Some(translate(code)),
+            Event::Mouse(_) | Event::Resize(_, _) => None,
+            Event::FocusGained | Event::FocusLost | Event::Paste(_) => None,
+        };
+
+        Ok(key)
+    }
+
+    fn translate(code: KeyCode) -> Key {
+        match code {
+            KeyCode::Char(c) => Key::Char(c),
+            KeyCode::Enter => Key::Enter,
+            KeyCode::Esc => Key::Esc,
+            KeyCode::Backspace => Key::Backspace,
+            KeyCode::Up => Key::Up,
+            KeyCode::Down => Key::Down,
+            KeyCode::Left => Key::Left,
+            KeyCode::Right => Key::Right,
+            KeyCode::PageUp => Key::PageUp,
+            KeyCode::PageDown => Key::PageDown,
+            _ => Key::Other,
+        }
+    }
+}
+
+#[cfg(feature = "termion")]
+mod backend {
+    use super::{Backend, DefaultTerminal, Key, Viewport};
+    use std::io::{self, Write};
+    use std::sync::mpsc::{self, Receiver, RecvTimeoutError};
+    use std::sync::Mutex;
+    use std::time::Duration;
+
+    use once_cell::sync::Lazy;
+    use ratatui::{Terminal, TerminalOptions};
+    use termion::input::TermRead;
+    use termion::raw::IntoRawMode;
+    use termion::screen::IntoAlternateScreen;
+
+    type RawHandle =
+        termion::raw::RawTerminal<termion::screen::AlternateScreen<io::Stdout>>;
+
+    /// Holds the real raw/alternate-screen handle so `teardown` can drop it
+    /// synchronously (which is what actually restores the terminal), rather
+    /// than relying on `Backend`'s own drop running during stack unwinding
+    /// — by then the panic hook has already printed the original message
+    /// into a still-raw terminal.
+    static HANDLE: Mutex<Option<RawHandle>> = Mutex::new(None);
+
+    /// A zero-sized `Write` impl that forwards through [`HANDLE`], so the
+    /// `Backend` doesn't need to own the raw terminal handle directly.
+    pub struct TermionWriter;
+
+    impl Write for TermionWriter {
+        fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+            match HANDLE.lock().unwrap().as_mut() {
+                Some(handle) => handle.write(buf),
+                None => Ok(buf.len()),
+            }
+        }
+
+        fn flush(&mut self) -> io::Result<()> {
+            match HANDLE.lock().unwrap().as_mut() {
+                Some(handle) => handle.flush(),
+                None => Ok(()),
+            }
+        }
+    }
+
+    pub fn setup(viewport: Viewport) -> io::Result<DefaultTerminal> {
+        // termion's alternate screen is baked into `RawHandle`'s type, so
+        // unlike the crossterm path an inline viewport here still shares
+        // the alternate screen; only the drawn region itself shrinks.
+        let screen = io::stdout().into_alternate_screen()?;
+        let raw = screen.into_raw_mode()?;
+        *HANDLE.lock().unwrap() = Some(raw);
+
+        let backend: Backend = ratatui::backend::TermionBackend::new(TermionWriter);
+        Terminal::with_options(backend, TerminalOptions { viewport })
+    }
+
+    pub fn teardown() -> io::Result<()> {
+        // Taking and dropping the handle here runs `RawTerminal`'s and
+        // `AlternateScreen`'s `Drop` impls immediately, synchronously
+        // restoring cooked mode and the main screen. Show the cursor first
+        // since afterwards `TermionWriter` (and thus `terminal.show_cursor()`)
+        // silently no-ops with the handle gone.
+        if let Some(mut handle) = HANDLE.lock().unwrap().take() {
+            write!(handle, "{}", termion::cursor::Show)?;
+            handle.flush()?;
+        }
+        Ok(())
+    }
+
+    /// Reads keys from stdin on a dedicated background thread and forwards
+    /// them through an `mpsc` channel, so [`poll_event`] can wait on
+    /// `recv_timeout` instead of blocking on termion's `stdin.keys()`
+    /// iterator, which has no notion of a timeout of its own.
+    static EVENTS: Lazy<Mutex<Receiver<Key>>> = Lazy::new(|| {
+        let (tx, rx) = mpsc::channel();
+        std::thread::spawn(move || {
+            for key in io::stdin().keys().flatten() {
+                if tx.send(translate(key)).is_err() {
+                    break;
+                }
+            }
+        });
+        Mutex::new(rx)
+    });
+
+    pub fn poll_event(timeout: Duration) -> io::Result<Option<Key>> {
+        match EVENTS.lock().unwrap().recv_timeout(timeout) {
+            Ok(key) => Ok(Some(key)),
+            Err(RecvTimeoutError::Timeout) => Ok(None),
+            Err(RecvTimeoutError::Disconnected) => Ok(None),
+        }
+    }
+
+    fn translate(key: termion::event::Key) -> Key {
+        use termion::event::Key as TKey;
+        match key {
+            TKey::Char('\n') => Key::Enter,
+            TKey::Char(c) => Key::Char(c),
+            TKey::Backspace => Key::Backspace,
+            TKey::Esc => Key::Esc,
+            TKey::Up => Key::Up,
+            TKey::Down => Key::Down,
+            TKey::Left => Key::Left,
+            TKey::Right => Key::Right,
+            TKey::PageUp => Key::PageUp,
+            TKey::PageDown => Key::PageDown,
+            _ => Key::Other,
+        }
+    }
+}
@@ -1,10 +1,118 @@
 use ratatui::{
-    layout::{Alignment},
+    layout::{Alignment, Constraint, Direction, Layout},
     style::{Modifier, Style},
     text::{Line, Span},
-    widgets::{Block, Borders, Paragraph, Wrap},
+    widgets::{Block, Borders, List, ListItem, Paragraph, Wrap},
 };
 
+/// Which part of a screen is currently capturing key input.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Focus {
+    /// Navigating/acting on a list of items.
+    List,
+    /// Typing into a single-line edit buffer.
+    Editor,
+    /// Reserved for screens that grow a command line (e.g. Obsidian search).
+    #[allow(dead_code)]
+    Command,
+}
+
+/// A single TODO entry.
+#[derive(Debug, Clone)]
+pub struct TodoItem {
+    pub text: String,
+    pub done: bool,
+}
+
+/// State for the TodoList screen: the items, which one is selected, and the
+/// buffer used while inserting or editing an item's text.
+#[derive(Debug, Clone, Default)]
+pub struct TodoListState {
+    pub items: Vec<TodoItem>,
+    pub selected: usize,
+    pub edit_buffer: String,
+    editing_index: Option<usize>,
+}
+
+impl TodoListState {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn move_down(&mut self) {
+        if !self.items.is_empty() {
+            self.selected = (self.selected + 1) % self.items.len();
+        }
+    }
+
+    pub fn move_up(&mut self) {
+        if !self.items.is_empty() {
+            self.selected = (self.selected + self.items.len() - 1) % self.items.len();
+        }
+    }
+
+    pub fn toggle_selected(&mut self) {
+        if let Some(item) = self.items.get_mut(self.selected) {
+            item.done = !item.done;
+        }
+    }
+
+    pub fn delete_selected(&mut self) {
+        if self.items.is_empty() {
+            return;
+        }
+        self.items.remove(self.selected);
+        if self.selected >= self.items.len() && self.selected > 0 {
+            self.selected -= 1;
+        }
+    }
+
+    /// Starts inserting a brand-new item after the current selection.
+    pub fn begin_insert(&mut self) {
+        self.editing_index = None;
+        self.edit_buffer.clear();
+    }
+
+    /// Starts editing the selected item's text in place. Returns `false`
+    /// (and does nothing) if there is no item to edit.
+    pub fn begin_edit_selected(&mut self) -> bool {
+        match self.items.get(self.selected) {
+            Some(item) => {
+                self.edit_buffer = item.text.clone();
+                self.editing_index = Some(self.selected);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Commits `edit_buffer` either as a new item or as the edited text of
+    /// the item that was being edited, then clears the buffer.
+    pub fn commit_edit(&mut self) {
+        let text = std::mem::take(&mut self.edit_buffer);
+        match self.editing_index.take() {
+            Some(index) => {
+                if let Some(item) = self.items.get_mut(index) {
+                    item.text = text;
+                }
+            }
+            None if !text.is_empty() => {
+                // Insert after the current selection, per `begin_insert`'s
+                // contract, rather than before it.
+                let insert_at = if self.items.is_empty() { 0 } else { self.selected + 1 };
+                self.items.insert(insert_at, TodoItem { text, done: false });
+                self.selected = insert_at;
+            }
+            None => {}
+        }
+    }
+
+    pub fn cancel_edit(&mut self) {
+        self.edit_buffer.clear();
+        self.editing_index = None;
+    }
+}
+
 pub fn draw_fullscreen_page(f: &mut ratatui::Frame, title: &str) {
     let size = f.size();
 
@@ -28,3 +136,93 @@ pub fn draw_fullscreen_page(f: &mut ratatui::Frame, title: &str) {
 
     f.render_widget(paragraph, size);
 }
+
+/// Renders the TodoList screen: a checkbox list with the selection
+/// highlighted, and an input line at the bottom when inserting/editing.
+pub fn draw_todo_list(f: &mut ratatui::Frame, state: &TodoListState, focus: Focus) {
+    let size = f.size();
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Min(3), Constraint::Length(3)].as_ref())
+        .split(size);
+
+    let items: Vec<ListItem> = state
+        .items
+        .iter()
+        .enumerate()
+        .map(|(i, item)| {
+            let checkbox = if item.done { "[x]" } else { "[ ]" };
+            let mut style = Style::default();
+            if item.done {
+                style = style.add_modifier(Modifier::CROSSED_OUT);
+            }
+            if i == state.selected {
+                style = style.add_modifier(Modifier::BOLD);
+            }
+            ListItem::new(Line::from(Span::styled(
+                format!("{checkbox} {}", item.text),
+                style,
+            )))
+        })
+        .collect();
+
+    let list = List::new(items)
+        .block(Block::default().title("TodoList").borders(Borders::ALL))
+        .highlight_style(Style::default().add_modifier(Modifier::REVERSED))
+        .highlight_symbol("> ");
+
+    let mut list_state = ratatui::widgets::ListState::default();
+    if !state.items.is_empty() {
+        list_state.select(Some(state.selected));
+    }
+    f.render_stateful_widget(list, chunks[0], &mut list_state);
+
+    let editor_title = if focus == Focus::Editor { "Editing (Enter to save, Esc to cancel)" } else { "j/k move, Space toggle, i insert, Enter edit, d delete, q back" };
+    let editor_text = if focus == Focus::Editor {
+        format!("{}_", state.edit_buffer)
+    } else {
+        String::new()
+    };
+    let editor = Paragraph::new(editor_text).block(Block::default().title(editor_title).borders(Borders::ALL));
+    f.render_widget(editor, chunks[1]);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn commit_edit_inserts_new_item_after_the_selection() {
+        let mut state = TodoListState {
+            items: vec![
+                TodoItem { text: "first".to_string(), done: false },
+                TodoItem { text: "second".to_string(), done: false },
+            ],
+            selected: 0,
+            ..TodoListState::new()
+        };
+
+        state.begin_insert();
+        state.edit_buffer = "inserted".to_string();
+        state.commit_edit();
+
+        assert_eq!(state.items[1].text, "inserted");
+        assert_eq!(state.selected, 1);
+    }
+
+    #[test]
+    fn commit_edit_updates_the_item_being_edited_in_place() {
+        let mut state = TodoListState {
+            items: vec![TodoItem { text: "first".to_string(), done: false }],
+            ..TodoListState::new()
+        };
+
+        assert!(state.begin_edit_selected());
+        state.edit_buffer = "edited".to_string();
+        state.commit_edit();
+
+        assert_eq!(state.items.len(), 1);
+        assert_eq!(state.items[0].text, "edited");
+    }
+}